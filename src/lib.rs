@@ -3,22 +3,44 @@
  * Licensed under the MIT License. See LICENSE in the project root for license information.
  */
 
+use std::cmp::Ordering;
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+mod version_req;
+
+pub use version_req::{ReqParseError, TinyVersionReq};
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
+#[derive(Debug, Clone)]
 pub struct TinyVersion {
     major: u32,
     minor: u32,
     patch: u32,
     pre_release: Option<String>,
+    build: Option<String>,
 }
 
+/// Build metadata is ignored when determining equality, as required by semver.
+impl PartialEq for TinyVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.major == other.major
+            && self.minor == other.minor
+            && self.patch == other.patch
+            && self.pre_release == other.pre_release
+    }
+}
+
+impl Eq for TinyVersion {}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum ParseError {
     InvalidFormat,
     InvalidNumber,
     InvalidPreRelease,
+    InvalidBuild,
 }
 
 #[derive(Debug, Eq, PartialEq)]
@@ -35,7 +57,8 @@ pub enum SplitError {
 impl FromStr for TinyVersion {
     type Err = ParseError;
 
-    /// Parses a version string in the format "major.minor.patch" or "major.minor.patch-pre_release".
+    /// Parses a version string in the format "major.minor.patch", optionally followed by
+    /// "-pre_release" and/or "+build".
     ///
     /// # Examples
     ///
@@ -46,9 +69,16 @@ impl FromStr for TinyVersion {
     ///
     /// let version: TinyVersion = "1.2.3-beta".parse().unwrap();
     /// assert_eq!(version.to_string(), "1.2.3-beta");
+    ///
+    /// let version: TinyVersion = "1.2.3-beta.2+build.42".parse().unwrap();
+    /// assert_eq!(version.to_string(), "1.2.3-beta.2+build.42");
     /// ```
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.splitn(2, '-');
+        let mut build_parts = s.splitn(2, '+');
+        let rest = build_parts.next().ok_or(ParseError::InvalidFormat)?;
+        let build_part = build_parts.next();
+
+        let mut parts = rest.splitn(2, '-');
         let version_part = parts.next().ok_or(ParseError::InvalidFormat)?;
         let pre_release_part = parts.next();
 
@@ -69,24 +99,28 @@ impl FromStr for TinyVersion {
 
         let pre_release = match pre_release_part {
             Some(s) => {
-                // Enforce that the pre-release part is non-empty
+                validate_pre_release(s)?;
+                Some(s.to_string())
+            }
+            None => None,
+        };
+
+        let build = match build_part {
+            Some(s) => {
+                // Enforce that the build part is non-empty
                 if s.is_empty() {
-                    return Err(ParseError::InvalidPreRelease);
+                    return Err(ParseError::InvalidBuild);
                 }
-                // Split the pre-release part by '.' to get individual identifiers
+                // Split the build part by '.' to get individual identifiers
                 let identifiers: Vec<&str> = s.split('.').collect();
                 if identifiers.iter().any(|id| id.is_empty()) {
-                    return Err(ParseError::InvalidPreRelease);
+                    return Err(ParseError::InvalidBuild);
                 }
                 for id in identifiers {
                     // Each identifier must contain only ASCII alphanumeric characters or hyphen.
+                    // Unlike pre-release identifiers, leading zeros are allowed.
                     if !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
-                        return Err(ParseError::InvalidPreRelease);
-                    }
-                    // If the identifier is numeric, it must not have leading zeros (except for "0").
-                    if id.chars().all(|c| c.is_ascii_digit()) && id.len() > 1 && id.starts_with('0')
-                    {
-                        return Err(ParseError::InvalidPreRelease);
+                        return Err(ParseError::InvalidBuild);
                     }
                 }
                 Some(s.to_string())
@@ -99,37 +133,187 @@ impl FromStr for TinyVersion {
             minor,
             patch,
             pre_release,
+            build,
         })
     }
 }
 
+/// Validates a pre-release string against the same grammar used by [`FromStr`](TinyVersion::from_str):
+/// non-empty, dot-separated identifiers of ASCII alphanumerics and hyphens, with numeric
+/// identifiers forbidden from having leading zeros (except for "0" itself).
+fn validate_pre_release(s: &str) -> Result<(), ParseError> {
+    // Enforce that the pre-release part is non-empty
+    if s.is_empty() {
+        return Err(ParseError::InvalidPreRelease);
+    }
+    // Split the pre-release part by '.' to get individual identifiers
+    let identifiers: Vec<&str> = s.split('.').collect();
+    if identifiers.iter().any(|id| id.is_empty()) {
+        return Err(ParseError::InvalidPreRelease);
+    }
+    for id in identifiers {
+        // Each identifier must contain only ASCII alphanumeric characters or hyphen.
+        if !id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+            return Err(ParseError::InvalidPreRelease);
+        }
+        // If the identifier is numeric, it must not have leading zeros (except for "0").
+        if id.chars().all(|c| c.is_ascii_digit()) && id.len() > 1 && id.starts_with('0') {
+            return Err(ParseError::InvalidPreRelease);
+        }
+    }
+    Ok(())
+}
+
 impl TinyVersion {
+    /// Creates a new version with no pre-release or build metadata.
+    #[must_use]
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+            pre_release: None,
+            build: None,
+        }
+    }
+
+    /// Sets the pre-release identifier, consuming and returning `self`.
+    ///
+    /// # Errors
+    /// Returns `ParseError::InvalidPreRelease` if `pre_release` does not conform to the
+    /// same grammar enforced by [`FromStr`](TinyVersion::from_str).
+    pub fn with_pre_release(mut self, pre_release: impl Into<String>) -> Result<Self, ParseError> {
+        let pre_release = pre_release.into();
+        validate_pre_release(&pre_release)?;
+        self.pre_release = Some(pre_release);
+        Ok(self)
+    }
+
+    #[must_use]
+    pub const fn major(&self) -> u32 {
+        self.major
+    }
+
+    #[must_use]
+    pub const fn minor(&self) -> u32 {
+        self.minor
+    }
+
+    #[must_use]
+    pub const fn patch(&self) -> u32 {
+        self.patch
+    }
+
+    #[must_use]
+    pub fn pre_release(&self) -> Option<&str> {
+        self.pre_release.as_deref()
+    }
+
+    /// Bumps `major` by one and resets `minor`, `patch` and any pre-release or build metadata.
+    pub fn increment_major(&mut self) {
+        self.major += 1;
+        self.minor = 0;
+        self.patch = 0;
+        self.pre_release = None;
+        self.build = None;
+    }
+
+    /// Bumps `minor` by one and resets `patch` and any pre-release or build metadata.
+    pub fn increment_minor(&mut self) {
+        self.minor += 1;
+        self.patch = 0;
+        self.pre_release = None;
+        self.build = None;
+    }
+
+    /// Bumps `patch` by one and clears any pre-release or build metadata.
+    pub fn increment_patch(&mut self) {
+        self.patch += 1;
+        self.pre_release = None;
+        self.build = None;
+    }
+
     /// # Errors
     /// Return `NameError` if the name is not conforming to `is_valid_name`.
     pub fn versioned_name(&self, name: &str) -> Result<String, NameError> {
         if !is_valid_name(name) {
             return Err(NameError::InvalidName(name.to_string()));
         }
-        let result = self.pre_release.as_ref().map_or_else(
-            || format!("{}-{}.{}.{}", name, self.major, self.minor, self.patch),
-            |pre| {
-                format!(
-                    "{}-{}.{}.{}-{}",
-                    name, self.major, self.minor, self.patch, pre
-                )
-            },
-        );
-
-        Ok(result)
+
+        Ok(format!("{}-{}", name, self))
     }
 }
 
 impl fmt::Display for TinyVersion {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self.pre_release {
-            Some(pre) => write!(f, "{}.{}.{}-{}", self.major, self.minor, self.patch, pre),
-            None => write!(f, "{}.{}.{}", self.major, self.minor, self.patch),
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if let Some(pre) = &self.pre_release {
+            write!(f, "-{pre}")?;
+        }
+        if let Some(build) = &self.build {
+            write!(f, "+{build}")?;
+        }
+        Ok(())
+    }
+}
+
+impl PartialOrd for TinyVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TinyVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.major
+            .cmp(&other.major)
+            .then_with(|| self.minor.cmp(&other.minor))
+            .then_with(|| self.patch.cmp(&other.patch))
+            .then_with(|| compare_pre_release(self.pre_release.as_deref(), other.pre_release.as_deref()))
+    }
+}
+
+/// Compares two optional pre-release strings following semver precedence rules.
+///
+/// A version without a pre-release has higher precedence than one with a pre-release.
+/// Otherwise, identifiers are compared left-to-right: numeric identifiers compare
+/// numerically and are always lower precedence than alphanumeric identifiers, which
+/// compare lexically by ASCII. If all shared identifiers are equal, the pre-release
+/// with more identifiers has higher precedence.
+fn compare_pre_release(a: Option<&str>, b: Option<&str>) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let a_ids = a.split('.');
+            let b_ids = b.split('.');
+            a_ids
+                .zip(b_ids)
+                .map(|(a_id, b_id)| compare_identifier(a_id, b_id))
+                .find(|&ordering| ordering != Ordering::Equal)
+                .unwrap_or_else(|| a.split('.').count().cmp(&b.split('.').count()))
+        }
+    }
+}
+
+/// Compares two pre-release identifiers per semver rules: numeric identifiers compare
+/// numerically, alphanumeric identifiers compare lexically by ASCII, and a purely
+/// numeric identifier always has lower precedence than an alphanumeric one.
+fn compare_identifier(a: &str, b: &str) -> Ordering {
+    let a_is_numeric = a.chars().all(|c| c.is_ascii_digit());
+    let b_is_numeric = b.chars().all(|c| c.is_ascii_digit());
+
+    match (a_is_numeric, b_is_numeric) {
+        (true, true) => {
+            // Numeric identifiers never have leading zeros (enforced at parse time), so
+            // comparing by length first and then lexically is equivalent to comparing
+            // the identifiers as integers, without risking overflow for long digit strings.
+            a.len().cmp(&b.len()).then_with(|| a.cmp(b))
         }
+        (true, false) => Ordering::Less,
+        (false, true) => Ordering::Greater,
+        (false, false) => a.cmp(b),
     }
 }
 
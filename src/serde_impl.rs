@@ -0,0 +1,32 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/tiny-ver
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::TinyVersion;
+
+impl Serialize for TinyVersion {
+    /// Serializes as the canonical version string, e.g. `"1.2.3-beta"`.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for TinyVersion {
+    /// Deserializes from the canonical version string, reusing [`FromStr`].
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        TinyVersion::from_str(&s).map_err(|e| D::Error::custom(format!("{e:?}")))
+    }
+}
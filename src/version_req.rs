@@ -0,0 +1,141 @@
+/*
+ * Copyright (c) Peter Bjorklund. All rights reserved. https://github.com/piot/tiny-ver
+ * Licensed under the MIT License. See LICENSE in the project root for license information.
+ */
+
+use std::str::FromStr;
+
+use crate::{ParseError, TinyVersion};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum ReqParseError {
+    Empty,
+    InvalidOperator,
+    InvalidVersion(ParseError),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Comparator {
+    Exact(TinyVersion),
+    Greater(TinyVersion),
+    GreaterEq(TinyVersion),
+    Less(TinyVersion),
+    LessEq(TinyVersion),
+    /// Inclusive lower bound, exclusive upper bound. Used for `^` and `~`.
+    Range {
+        lower: TinyVersion,
+        upper: TinyVersion,
+    },
+}
+
+impl Comparator {
+    fn matches(&self, v: &TinyVersion) -> bool {
+        match self {
+            Self::Exact(version) => prerelease_allowed(version, v) && v == version,
+            Self::Greater(version) => prerelease_allowed(version, v) && v > version,
+            Self::GreaterEq(version) => prerelease_allowed(version, v) && v >= version,
+            Self::Less(version) => prerelease_allowed(version, v) && v < version,
+            Self::LessEq(version) => prerelease_allowed(version, v) && v <= version,
+            Self::Range { lower, upper } => {
+                prerelease_allowed(lower, v) && v >= lower && v < upper
+            }
+        }
+    }
+}
+
+/// A version with a pre-release only satisfies a comparator when that comparator's own
+/// version names the same `major.minor.patch` and itself has a pre-release.
+fn prerelease_allowed(comparator_version: &TinyVersion, v: &TinyVersion) -> bool {
+    if v.pre_release.is_none() {
+        return true;
+    }
+
+    comparator_version.major == v.major
+        && comparator_version.minor == v.minor
+        && comparator_version.patch == v.patch
+        && comparator_version.pre_release.is_some()
+}
+
+fn parse_comparator(s: &str) -> Result<Comparator, ReqParseError> {
+    let s = s.trim();
+
+    if let Some(rest) = s.strip_prefix(">=") {
+        return parse_version(rest).map(Comparator::GreaterEq);
+    }
+    if let Some(rest) = s.strip_prefix("<=") {
+        return parse_version(rest).map(Comparator::LessEq);
+    }
+    if let Some(rest) = s.strip_prefix('>') {
+        return parse_version(rest).map(Comparator::Greater);
+    }
+    if let Some(rest) = s.strip_prefix('<') {
+        return parse_version(rest).map(Comparator::Less);
+    }
+    if let Some(rest) = s.strip_prefix('=') {
+        return parse_version(rest).map(Comparator::Exact);
+    }
+    if let Some(rest) = s.strip_prefix('^') {
+        let lower = parse_version(rest)?;
+        let upper = if lower.major > 0 {
+            TinyVersion::new(lower.major + 1, 0, 0)
+        } else if lower.minor > 0 {
+            TinyVersion::new(0, lower.minor + 1, 0)
+        } else {
+            TinyVersion::new(0, 0, lower.patch + 1)
+        };
+        return Ok(Comparator::Range { lower, upper });
+    }
+    if let Some(rest) = s.strip_prefix('~') {
+        let lower = parse_version(rest)?;
+        let upper = TinyVersion::new(lower.major, lower.minor + 1, 0);
+        return Ok(Comparator::Range { lower, upper });
+    }
+
+    Err(ReqParseError::InvalidOperator)
+}
+
+fn parse_version(s: &str) -> Result<TinyVersion, ReqParseError> {
+    s.trim().parse().map_err(ReqParseError::InvalidVersion)
+}
+
+/// A version requirement made up of one or more comma-separated comparators, e.g.
+/// `">=1.2.3, <2.0.0"` or `"^1.2.3"`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TinyVersionReq {
+    comparators: Vec<Comparator>,
+}
+
+impl FromStr for TinyVersionReq {
+    type Err = ReqParseError;
+
+    /// Parses a comma-separated list of version comparators.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tiny_ver::TinyVersionReq;
+    /// let req: TinyVersionReq = "^1.2.3".parse().unwrap();
+    /// assert!(req.matches(&"1.5.0".parse().unwrap()));
+    /// assert!(!req.matches(&"2.0.0".parse().unwrap()));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ReqParseError::Empty);
+        }
+
+        let comparators = s
+            .split(',')
+            .map(parse_comparator)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { comparators })
+    }
+}
+
+impl TinyVersionReq {
+    /// Returns true if `v` satisfies every comparator in this requirement.
+    #[must_use]
+    pub fn matches(&self, v: &TinyVersion) -> bool {
+        self.comparators.iter().all(|c| c.matches(v))
+    }
+}
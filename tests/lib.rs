@@ -3,7 +3,7 @@
  * Licensed under the MIT License. See LICENSE in the project root for license information.
  */
 
-use tiny_ver::{is_valid_name, ParseError, TinyVersion};
+use tiny_ver::{is_valid_name, ParseError, ReqParseError, TinyVersion, TinyVersionReq};
 
 #[test]
 fn add_to_name() {
@@ -93,3 +93,231 @@ fn invalid_characters() {
     assert!(!is_valid_name("foo1bar"));
     assert!(!is_valid_name("foo!bar"));
 }
+
+#[test]
+fn ordering_precedence() {
+    let versions: Vec<TinyVersion> = [
+        "1.0.0-alpha",
+        "1.0.0-alpha.1",
+        "1.0.0-alpha.beta",
+        "1.0.0-beta",
+        "1.0.0-rc.1",
+        "1.0.0",
+    ]
+    .iter()
+    .map(|s| s.parse().unwrap())
+    .collect();
+
+    for pair in versions.windows(2) {
+        assert!(
+            pair[0] < pair[1],
+            "expected {} < {}",
+            pair[0],
+            pair[1]
+        );
+    }
+}
+
+#[test]
+fn ordering_numeric_components() {
+    let v1: TinyVersion = "1.2.3".parse().unwrap();
+    let v2: TinyVersion = "1.10.0".parse().unwrap();
+    assert!(v1 < v2);
+}
+
+#[test]
+fn build_metadata_round_trip() {
+    let version: TinyVersion = "1.2.3-beta.2+build.42".parse().unwrap();
+    assert_eq!(version.to_string(), "1.2.3-beta.2+build.42");
+
+    let version: TinyVersion = "1.2.3+20130313144700".parse().unwrap();
+    assert_eq!(version.to_string(), "1.2.3+20130313144700");
+}
+
+#[test]
+fn build_metadata_in_versioned_name() {
+    let version: TinyVersion = "1.2.3+build.5".parse().unwrap();
+    assert_eq!(
+        version.versioned_name("myapp").unwrap(),
+        "myapp-1.2.3+build.5"
+    );
+}
+
+#[test]
+fn build_metadata_ignored_in_equality_and_ordering() {
+    let a: TinyVersion = "1.0.0+a".parse().unwrap();
+    let b: TinyVersion = "1.0.0+b".parse().unwrap();
+    assert_eq!(a, b);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+}
+
+#[test]
+fn build_metadata_leading_zeros_allowed() {
+    let version: TinyVersion = "1.2.3+01".parse().unwrap();
+    assert_eq!(version.to_string(), "1.2.3+01");
+}
+
+#[test]
+fn invalid_build_metadata() {
+    let version = "1.2.3+!nope".parse::<TinyVersion>();
+    assert_eq!(version, Err(ParseError::InvalidBuild));
+
+    let version = "1.2.3+".parse::<TinyVersion>();
+    assert_eq!(version, Err(ParseError::InvalidBuild));
+}
+
+#[test]
+fn ordering_long_numeric_identifier_does_not_overflow() {
+    let v1: TinyVersion = "1.0.0-1".parse().unwrap();
+    let v2: TinyVersion = "1.0.0-99999999999999999999".parse().unwrap();
+    assert!(v1 < v2);
+}
+
+#[test]
+fn req_exact() {
+    let req: TinyVersionReq = "=1.2.3".parse().unwrap();
+    assert!(req.matches(&"1.2.3".parse().unwrap()));
+    assert!(!req.matches(&"1.2.4".parse().unwrap()));
+}
+
+#[test]
+fn req_comparison_operators() {
+    let req: TinyVersionReq = ">1.2.3, <=2.0.0".parse().unwrap();
+    assert!(!req.matches(&"1.2.3".parse().unwrap()));
+    assert!(req.matches(&"1.2.4".parse().unwrap()));
+    assert!(req.matches(&"2.0.0".parse().unwrap()));
+    assert!(!req.matches(&"2.0.1".parse().unwrap()));
+}
+
+#[test]
+fn req_caret() {
+    let req: TinyVersionReq = "^1.2.3".parse().unwrap();
+    assert!(req.matches(&"1.2.3".parse().unwrap()));
+    assert!(req.matches(&"1.5.0".parse().unwrap()));
+    assert!(!req.matches(&"2.0.0".parse().unwrap()));
+    assert!(!req.matches(&"1.2.2".parse().unwrap()));
+}
+
+#[test]
+fn req_caret_zero_major() {
+    let req: TinyVersionReq = "^0.2.3".parse().unwrap();
+    assert!(req.matches(&"0.2.3".parse().unwrap()));
+    assert!(req.matches(&"0.2.9".parse().unwrap()));
+    assert!(!req.matches(&"0.3.0".parse().unwrap()));
+}
+
+#[test]
+fn req_tilde() {
+    let req: TinyVersionReq = "~1.2.3".parse().unwrap();
+    assert!(req.matches(&"1.2.3".parse().unwrap()));
+    assert!(req.matches(&"1.2.9".parse().unwrap()));
+    assert!(!req.matches(&"1.3.0".parse().unwrap()));
+}
+
+#[test]
+fn req_pre_release_only_matches_same_triple() {
+    let req: TinyVersionReq = ">=1.2.3-alpha".parse().unwrap();
+    assert!(req.matches(&"1.2.3-alpha".parse().unwrap()));
+    assert!(req.matches(&"1.2.3-beta".parse().unwrap()));
+    assert!(!req.matches(&"1.3.0-alpha".parse().unwrap()));
+    assert!(req.matches(&"1.2.4".parse().unwrap()));
+}
+
+#[test]
+fn req_invalid_operator() {
+    let req = "1.2.3".parse::<TinyVersionReq>();
+    assert_eq!(req, Err(ReqParseError::InvalidOperator));
+}
+
+#[test]
+fn req_empty() {
+    let req = "".parse::<TinyVersionReq>();
+    assert_eq!(req, Err(ReqParseError::Empty));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_round_trip() {
+    let version: TinyVersion = "1.2.3-beta".parse().unwrap();
+    let json = serde_json::to_string(&version).unwrap();
+    assert_eq!(json, "\"1.2.3-beta\"");
+
+    let round_tripped: TinyVersion = serde_json::from_str(&json).unwrap();
+    assert_eq!(round_tripped, version);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn serde_invalid_string() {
+    let result: Result<TinyVersion, _> = serde_json::from_str("\"not-a-version\"");
+    assert!(result.is_err());
+}
+
+#[test]
+fn constructor_and_accessors() {
+    let version = TinyVersion::new(1, 2, 3).with_pre_release("beta").unwrap();
+    assert_eq!(version.major(), 1);
+    assert_eq!(version.minor(), 2);
+    assert_eq!(version.patch(), 3);
+    assert_eq!(version.pre_release(), Some("beta"));
+    assert_eq!(version.to_string(), "1.2.3-beta");
+}
+
+#[test]
+fn constructor_without_pre_release() {
+    let version = TinyVersion::new(1, 0, 0);
+    assert_eq!(version.pre_release(), None);
+    assert_eq!(version.to_string(), "1.0.0");
+}
+
+#[test]
+fn increment_patch_clears_pre_release() {
+    let mut version = TinyVersion::new(1, 2, 3).with_pre_release("alpha").unwrap();
+    version.increment_patch();
+    assert_eq!(version.to_string(), "1.2.4");
+}
+
+#[test]
+fn increment_minor_resets_patch() {
+    let mut version = TinyVersion::new(1, 2, 3).with_pre_release("alpha").unwrap();
+    version.increment_minor();
+    assert_eq!(version.to_string(), "1.3.0");
+}
+
+#[test]
+fn increment_clears_build_metadata() {
+    let mut version: TinyVersion = "1.2.3+exp.sha.deadbeef".parse().unwrap();
+    version.increment_patch();
+    assert_eq!(version.to_string(), "1.2.4");
+
+    let mut version: TinyVersion = "1.2.3+exp.sha.deadbeef".parse().unwrap();
+    version.increment_minor();
+    assert_eq!(version.to_string(), "1.3.0");
+
+    let mut version: TinyVersion = "1.2.3+exp.sha.deadbeef".parse().unwrap();
+    version.increment_major();
+    assert_eq!(version.to_string(), "2.0.0");
+}
+
+#[test]
+fn increment_major_resets_minor_and_patch() {
+    let mut version = TinyVersion::new(1, 2, 3).with_pre_release("alpha").unwrap();
+    version.increment_major();
+    assert_eq!(version.to_string(), "2.0.0");
+}
+
+#[test]
+fn with_pre_release_validates_grammar() {
+    let result = TinyVersion::new(1, 2, 3).with_pre_release("not valid!!");
+    assert_eq!(result, Err(ParseError::InvalidPreRelease));
+
+    let result = TinyVersion::new(1, 2, 3).with_pre_release("rc.01");
+    assert_eq!(result, Err(ParseError::InvalidPreRelease));
+}
+
+#[test]
+fn with_pre_release_round_trips_through_from_str() {
+    let version = TinyVersion::new(1, 2, 3).with_pre_release("rc.1").unwrap();
+    let round_tripped: TinyVersion = version.to_string().parse().unwrap();
+    assert_eq!(round_tripped, version);
+}